@@ -1,14 +1,21 @@
 #![feature(let_chains)]
 use anyhow::{Error, Result};
 use git2::{Remote, Repository, RepositoryOpenFlags, Status};
-use options::{get_options, Options};
+use options::{get_options, Options, Output};
+use serde::Serialize;
 use std::{fs::File, process::Command};
 mod options;
 
 fn main() {
     let options = get_options();
     let print_error = options.print_error;
-    match format_status(options) {
+    let result = match options.output {
+        Output::Json => {
+            collect_status(options).and_then(|record| Ok(serde_json::to_string(&record)?))
+        }
+        Output::Plain => format_status(options),
+    };
+    match result {
         Err(e) => {
             if print_error {
                 eprintln!("{e}");
@@ -19,6 +26,55 @@ fn main() {
     }
 }
 
+/// Every datum grompt computes about a repository, for the `--output json` mode.
+#[derive(Debug, Serialize)]
+struct StatusRecord {
+    branch: String,
+    unstaged: usize,
+    staged: usize,
+    ahead: usize,
+    behind: usize,
+    remote_url: Option<String>,
+    remote_icon: Option<String>,
+    minutes_since_fetch: Option<u64>,
+    in_nix_shell: bool,
+}
+
+fn collect_status(options: Options) -> Result<StatusRecord> {
+    let repo = Repository::open_ext(
+        options.path,
+        RepositoryOpenFlags::CROSS_FS,
+        &[] as &[&std::ffi::OsStr],
+    )?;
+
+    let head = repo.head()?;
+    let branch = head
+        .shorthand()
+        .ok_or(Error::msg("Failed to get branch name"))?
+        .to_string();
+
+    let (unstaged, staged) = repo_status(&repo)?;
+    let (ahead, behind) = commit_status(&repo);
+    let remote_url = get_remote(&repo)
+        .ok()
+        .and_then(|remote| remote.url().map(str::to_string));
+    let remote_icon = get_icon(&repo, options.icon_override, options.icon_color).ok();
+    let minutes_since_fetch = minutes_since_last(&repo).ok();
+    let in_nix_shell = std::env::var("IN_NIX_SHELL").is_ok();
+
+    Ok(StatusRecord {
+        branch,
+        unstaged,
+        staged,
+        ahead,
+        behind,
+        remote_url,
+        remote_icon,
+        minutes_since_fetch,
+        in_nix_shell,
+    })
+}
+
 fn create_icons(icon_override: Vec<String>) -> Vec<(String, String, Option<[u8; 3]>)> {
     let icons = [
         (
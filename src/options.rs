@@ -1,5 +1,5 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use serde::{Deserialize, Serialize};
 use std::{fs::File, io::Read, path::PathBuf};
 
@@ -26,6 +26,17 @@ fn default_commit_behind() -> String {
     "\u{eaa1}".into()
 }
 
+/// How the resulting status should be printed.
+#[derive(Debug, Clone, Copy, Default, ValueEnum, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Output {
+    /// The usual pre-styled, single-line string meant for a prompt.
+    #[default]
+    Plain,
+    /// A structured record containing every datum grompt computes, serialized as JSON.
+    Json,
+}
+
 #[derive(Parser, Debug, Serialize, Deserialize)]
 #[command(author = "loafey", version = "0.5", about = "
 A tool to get the status of your git repos.
@@ -133,6 +144,12 @@ pub struct Options {
     #[arg(long = "nix-icon", default_value = "\u{f313} ")]
     #[serde(default)]
     pub nix_symbol: String,
+
+    /// Output mode. `plain` prints the usual formatted prompt string, `json` prints a
+    /// structured record of every datum grompt computes, for scripting and other tooling.
+    #[arg(long = "output", value_enum, default_value = "plain")]
+    #[serde(default)]
+    pub output: Output,
 }
 
 #[allow(unused)]